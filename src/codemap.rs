@@ -0,0 +1,255 @@
+extern crate alloc;
+use alloc::vec::Vec;
+
+use crate::json::{
+	CloseCurlyBracketChar, CloseSquareBracketChar, CommaChar, JsonValue, OpenCurlyBracketChar,
+	OpenSquareBracketChar, String as JsonString, Value, Whitespace,
+};
+use crate::parser::{Error, Input, Parser, ResultOf};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Span<P> {
+	pub start: P,
+	pub end: P,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EntryKind {
+	Object,
+	Array,
+	Member,
+	Scalar,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Entry<P> {
+	pub span: Span<P>,
+	pub kind: EntryKind,
+}
+
+// A parallel record of where every node of a parsed `JsonValue` came from in
+// the source text. Entries are appended in the same pre-order a caller would
+// visit them while walking the resulting `JsonValue`, so the index handed
+// back by `begin` stays stable and can be used to look the node back up.
+pub struct CodeMap<P> {
+	entries: Vec<Entry<P>>,
+}
+
+impl<P: Copy> CodeMap<P> {
+	fn new() -> Self {
+		CodeMap { entries: Vec::new() }
+	}
+
+	fn begin(&mut self, start: P, kind: EntryKind) -> usize {
+		let index = self.entries.len();
+		self.entries.push(Entry {
+			span: Span { start, end: start },
+			kind,
+		});
+		index
+	}
+
+	fn finish(&mut self, index: usize, end: P) {
+		self.entries[index].span.end = end;
+	}
+
+	pub fn get(&self, index: usize) -> Option<&Entry<P>> {
+		self.entries.get(index)
+	}
+
+	pub fn len(&self) -> usize {
+		self.entries.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.entries.is_empty()
+	}
+}
+
+// Top-level entry point that parses a document the same way `Json` does, but
+// also returns a `CodeMap` of where every object, array, member key, and
+// scalar came from. Kept separate from the ordinary `Value` parser so
+// `JsonValue` and its `PartialEq` impl stay untouched.
+pub fn parse<I: Input>(input: &I, current: I::Position) -> ResultOf<I, (JsonValue, CodeMap<I::Position>)>
+where
+	I::Position: Copy,
+{
+	let mut map = CodeMap::new();
+	let (_, next) = <Whitespace as Parser<I>>::parse(input, current)?;
+	let (value, next) = parse_value(input, next, &mut map)?;
+	let (_, next) = <Whitespace as Parser<I>>::parse(input, next)?;
+	Ok(((value, map), next))
+}
+
+fn parse_value<I: Input>(
+	input: &I,
+	current: I::Position,
+	map: &mut CodeMap<I::Position>,
+) -> ResultOf<I, JsonValue>
+where
+	I::Position: Copy,
+{
+	if let Ok((c, _)) = input.next(current) {
+		if c == '{' {
+			return parse_object(input, current, map);
+		}
+		if c == '[' {
+			return parse_array(input, current, map);
+		}
+	}
+	let entry_index = map.begin(current, EntryKind::Scalar);
+	let (value, next) = <Value as Parser<I>>::parse(input, current)?;
+	map.finish(entry_index, next);
+	Ok((value, next))
+}
+
+fn parse_element<I: Input>(
+	input: &I,
+	current: I::Position,
+	map: &mut CodeMap<I::Position>,
+) -> ResultOf<I, JsonValue>
+where
+	I::Position: Copy,
+{
+	let (_, next) = <Whitespace as Parser<I>>::parse(input, current)?;
+	let (value, next) = parse_value(input, next, map)?;
+	let (_, next) = <Whitespace as Parser<I>>::parse(input, next)?;
+	Ok((value, next))
+}
+
+fn parse_member<I: Input>(
+	input: &I,
+	current: I::Position,
+	map: &mut CodeMap<I::Position>,
+) -> ResultOf<I, (Vec<char>, JsonValue)>
+where
+	I::Position: Copy,
+{
+	let (_, next) = <Whitespace as Parser<I>>::parse(input, current)?;
+	let key_start = next;
+	let entry_index = map.begin(key_start, EntryKind::Member);
+	let (key, next) = <JsonString as Parser<I>>::parse(input, next)
+		.map_err(|e| e.add_reason(Some(current), "Member"))?;
+	map.finish(entry_index, next);
+	let (_, next) = <Whitespace as Parser<I>>::parse(input, next)?;
+	let next = input
+		.next(next)
+		.and_then(|(c, next)| {
+			if c == ':' {
+				Ok(next)
+			} else {
+				Err(input.error_at(next, "Character"))
+			}
+		})
+		.map_err(|e| e.add_reason(Some(current), "Member"))?;
+	let (value, next) = parse_element(input, next, map)?;
+	Ok(((key, value), next))
+}
+
+// Mirrors `Members`: one member, then zero or more `, Member` repetitions,
+// reusing the real `CommaChar` token rather than matching a raw `,`.
+fn parse_members<I: Input>(
+	input: &I,
+	current: I::Position,
+	map: &mut CodeMap<I::Position>,
+) -> ResultOf<I, Vec<(Vec<char>, JsonValue)>>
+where
+	I::Position: Copy,
+{
+	let mut members = Vec::new();
+	let (member, mut next) = parse_member(input, current, map)?;
+	members.push(member);
+	loop {
+		let (_, comma_pos) = <Whitespace as Parser<I>>::parse(input, next)?;
+		match <CommaChar as Parser<I>>::parse(input, comma_pos) {
+			Ok((_, after_comma)) => {
+				let (member, member_next) = parse_member(input, after_comma, map)?;
+				members.push(member);
+				next = member_next;
+			}
+			Err(_) => {
+				next = comma_pos;
+				break;
+			}
+		}
+	}
+	Ok((members, next))
+}
+
+// Mirrors `Object`: `{`, then `OneOf<Members, Whitespace>` so an empty
+// object is allowed, then `}`.
+fn parse_object<I: Input>(
+	input: &I,
+	current: I::Position,
+	map: &mut CodeMap<I::Position>,
+) -> ResultOf<I, JsonValue>
+where
+	I::Position: Copy,
+{
+	let entry_index = map.begin(current, EntryKind::Object);
+	let (_, next) = <OpenCurlyBracketChar as Parser<I>>::parse(input, current)?;
+	let mark = map.entries.len();
+	let (members, next) = match parse_members(input, next, map) {
+		Ok(result) => result,
+		Err(_) => {
+			// Drop entries the failed member attempt left behind, so an empty
+			// object records no member.
+			map.entries.truncate(mark);
+			let (_, next) = <Whitespace as Parser<I>>::parse(input, next)?;
+			(Vec::new(), next)
+		}
+	};
+	let (_, next) = <CloseCurlyBracketChar as Parser<I>>::parse(input, next)?;
+	map.finish(entry_index, next);
+	Ok((JsonValue::Object(members), next))
+}
+
+// Mirrors `Elements`: one element, then zero or more `, Element`
+// repetitions, reusing the real `CommaChar` token rather than matching a
+// raw `,`.
+fn parse_elements<I: Input>(
+	input: &I,
+	current: I::Position,
+	map: &mut CodeMap<I::Position>,
+) -> ResultOf<I, Vec<JsonValue>>
+where
+	I::Position: Copy,
+{
+	let mut elements = Vec::new();
+	let (element, mut next) = parse_element(input, current, map)?;
+	elements.push(element);
+	loop {
+		let (_, comma_pos) = <Whitespace as Parser<I>>::parse(input, next)?;
+		match <CommaChar as Parser<I>>::parse(input, comma_pos) {
+			Ok((_, after_comma)) => {
+				let (element, element_next) = parse_element(input, after_comma, map)?;
+				elements.push(element);
+				next = element_next;
+			}
+			Err(_) => {
+				next = comma_pos;
+				break;
+			}
+		}
+	}
+	Ok((elements, next))
+}
+
+// Mirrors `Array`: `[`, `Elements` (at least one element, same as the real
+// `Array` parser), `]` — no empty-array special case, so this stays in
+// agreement with what `Array` actually accepts.
+fn parse_array<I: Input>(
+	input: &I,
+	current: I::Position,
+	map: &mut CodeMap<I::Position>,
+) -> ResultOf<I, JsonValue>
+where
+	I::Position: Copy,
+{
+	let entry_index = map.begin(current, EntryKind::Array);
+	let (_, next) = <OpenSquareBracketChar as Parser<I>>::parse(input, current)?;
+	let (elements, next) = parse_elements(input, next, map)?;
+	let (_, next) = <CloseSquareBracketChar as Parser<I>>::parse(input, next)?;
+	map.finish(entry_index, next);
+	Ok((JsonValue::Array(elements), next))
+}
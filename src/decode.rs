@@ -0,0 +1,209 @@
+extern crate alloc;
+use alloc::{string::String as AllocString, vec::Vec};
+
+use crate::impls::SimpleError;
+use crate::json::JsonValue;
+use crate::parser::Error;
+
+pub trait Decoder {
+	fn read_struct<T, F>(&mut self, f: F) -> Result<T, SimpleError>
+	where
+		F: FnOnce(&mut Self) -> Result<T, SimpleError>;
+
+	fn read_struct_field<T, F>(&mut self, name: &'static str, f: F) -> Result<T, SimpleError>
+	where
+		F: FnOnce(&mut Self) -> Result<T, SimpleError>;
+
+	fn read_seq<T, F>(&mut self, f: F) -> Result<T, SimpleError>
+	where
+		F: FnOnce(&mut Self, usize) -> Result<T, SimpleError>;
+
+	fn read_seq_elt<T, F>(&mut self, index: usize, f: F) -> Result<T, SimpleError>
+	where
+		F: FnOnce(&mut Self) -> Result<T, SimpleError>;
+
+	fn read_option<T, F>(&mut self, f: F) -> Result<T, SimpleError>
+	where
+		F: FnOnce(&mut Self, bool) -> Result<T, SimpleError>;
+
+	fn read_i64(&mut self) -> Result<i64, SimpleError>;
+	fn read_f64(&mut self) -> Result<f64, SimpleError>;
+	fn read_bool(&mut self) -> Result<bool, SimpleError>;
+	fn read_str(&mut self) -> Result<AllocString, SimpleError>;
+}
+
+pub trait Decodable: Sized {
+	fn decode<D: Decoder>(d: &mut D) -> Result<Self, SimpleError>;
+}
+
+pub fn decode<T: Decodable>(value: &JsonValue) -> Result<T, SimpleError> {
+	let mut decoder = JsonDecoder::new(value);
+	T::decode(&mut decoder)
+}
+
+const MISSING: JsonValue = JsonValue::Null;
+
+pub struct JsonDecoder<'a> {
+	stack: Vec<&'a JsonValue>,
+}
+
+impl<'a> JsonDecoder<'a> {
+	pub fn new(value: &'a JsonValue) -> Self {
+		JsonDecoder {
+			stack: alloc::vec![value],
+		}
+	}
+
+	fn current(&self) -> &'a JsonValue {
+		self.stack[self.stack.len() - 1]
+	}
+}
+
+impl<'a> Decoder for JsonDecoder<'a> {
+	fn read_struct<T, F>(&mut self, f: F) -> Result<T, SimpleError>
+	where
+		F: FnOnce(&mut Self) -> Result<T, SimpleError>,
+	{
+		self.current()
+			.get_object()
+			.map_err(|e| e.add_reason(None, "expected an object"))?;
+		f(self)
+	}
+
+	fn read_struct_field<T, F>(&mut self, name: &'static str, f: F) -> Result<T, SimpleError>
+	where
+		F: FnOnce(&mut Self) -> Result<T, SimpleError>,
+	{
+		let object = self.current().get_object()?;
+		// A missing key reads as `Null` rather than erroring here, the same
+		// as an explicit `null` value would: `Option<T>::decode` goes
+		// through `read_option`'s `is_null` check and resolves to `None`,
+		// while any other `T` still fails, with the error of its own reader
+		// instead of a separate "missing field".
+		let field = object
+			.iter()
+			.find(|(key, _)| key.iter().collect::<AllocString>() == name)
+			.map(|(_, value)| value)
+			.unwrap_or(&MISSING);
+		self.stack.push(field);
+		let result = f(self);
+		self.stack.pop();
+		result.map_err(|e| e.add_reason(None, name))
+	}
+
+	fn read_seq<T, F>(&mut self, f: F) -> Result<T, SimpleError>
+	where
+		F: FnOnce(&mut Self, usize) -> Result<T, SimpleError>,
+	{
+		let array = self
+			.current()
+			.get_array()
+			.map_err(|e| e.add_reason(None, "expected an array"))?;
+		f(self, array.len())
+	}
+
+	fn read_seq_elt<T, F>(&mut self, index: usize, f: F) -> Result<T, SimpleError>
+	where
+		F: FnOnce(&mut Self) -> Result<T, SimpleError>,
+	{
+		let array = self.current().get_array()?;
+		let element = array
+			.get(index)
+			.ok_or_else(|| SimpleError::plain_str("sequence index out of bounds"))?;
+		self.stack.push(element);
+		let result = f(self);
+		self.stack.pop();
+		// `index` can't be folded into the reason here: `Error::add_reason`
+		// only takes `&'static str`, so a runtime array index has nowhere to
+		// go. The reason stays a generic "sequence element" until that trait
+		// grows a way to carry non-static data.
+		result.map_err(|e| e.add_reason(None, "sequence element"))
+	}
+
+	fn read_option<T, F>(&mut self, f: F) -> Result<T, SimpleError>
+	where
+		F: FnOnce(&mut Self, bool) -> Result<T, SimpleError>,
+	{
+		if self.current().is_null() {
+			f(self, false)
+		} else {
+			f(self, true)
+		}
+	}
+
+	fn read_i64(&mut self) -> Result<i64, SimpleError> {
+		read_integer(self.current())
+	}
+
+	fn read_f64(&mut self) -> Result<f64, SimpleError> {
+		self.current().get_number_f64()
+	}
+
+	fn read_bool(&mut self) -> Result<bool, SimpleError> {
+		self.current().get_bool()
+	}
+
+	fn read_str(&mut self) -> Result<AllocString, SimpleError> {
+		self.current().get_string()
+	}
+}
+
+// Only accepts `NumberValue`s that represent a whole number exactly, so a
+// config field typed as an integer never silently truncates "3.5".
+fn read_integer(value: &JsonValue) -> Result<i64, SimpleError> {
+	if let JsonValue::Number(num) = value {
+		if num.fraction_length != 0 || num.exponent < 0 {
+			return Err(SimpleError::plain_str("expected an integer, found a fractional number"));
+		}
+		let scale = 10i64
+			.checked_pow(num.exponent as u32)
+			.ok_or_else(|| SimpleError::plain_str("exponent too large"))?;
+		return num
+			.integer
+			.checked_mul(scale)
+			.ok_or_else(|| SimpleError::plain_str("integer overflow"));
+	}
+	Err(SimpleError::plain_str("expected a number"))
+}
+
+impl Decodable for i64 {
+	fn decode<D: Decoder>(d: &mut D) -> Result<Self, SimpleError> {
+		d.read_i64()
+	}
+}
+
+impl Decodable for f64 {
+	fn decode<D: Decoder>(d: &mut D) -> Result<Self, SimpleError> {
+		d.read_f64()
+	}
+}
+
+impl Decodable for bool {
+	fn decode<D: Decoder>(d: &mut D) -> Result<Self, SimpleError> {
+		d.read_bool()
+	}
+}
+
+impl Decodable for AllocString {
+	fn decode<D: Decoder>(d: &mut D) -> Result<Self, SimpleError> {
+		d.read_str()
+	}
+}
+
+impl<T: Decodable> Decodable for Vec<T> {
+	fn decode<D: Decoder>(d: &mut D) -> Result<Self, SimpleError> {
+		d.read_seq(|d, len| {
+			let mut result = Vec::with_capacity(len);
+			for index in 0..len {
+				result.push(d.read_seq_elt(index, |d| T::decode(d))?);
+			}
+			Ok(result)
+		})
+	}
+}
+
+impl<T: Decodable> Decodable for Option<T> {
+	fn decode<D: Decoder>(d: &mut D) -> Result<Self, SimpleError> {
+		d.read_option(|d, has_value| if has_value { Ok(Some(T::decode(d)?)) } else { Ok(None) })
+	}
+}
@@ -0,0 +1,118 @@
+extern crate alloc;
+use alloc::{boxed::Box, format, string::String, vec, vec::Vec};
+
+use crate::parser::{Concat, Null, OneOf, OneOrMore, ZeroOrMore, ZeroOrOne};
+
+// An EBNF fragment describing the shape a `Parser` combinator accepts. Every
+// combinator in `parser.rs` already encodes this structure in its type; this
+// just makes it readable at runtime.
+// `Rule`'s body is a function pointer rather than an eagerly-built `Grammar`,
+// because the crate's grammar is mutually recursive (e.g. `Object` reaches
+// back to itself through `Members`, `Member`, `Element`, `Value`). Deferring
+// construction until a rule is actually expanded, together with the `seen`
+// guard in `collect_rules`, is what keeps that recursion from looping forever.
+// No `PartialEq`: `Rule` carries a `fn() -> Grammar`, and comparing function
+// pointers is unreliable across optimizations (two rules with identical
+// bodies can be merged by the compiler into the same address). Nothing in
+// the crate actually needs to compare `Grammar` values.
+#[derive(Debug, Clone)]
+pub enum Grammar {
+	Terminal(&'static str),
+	Rule(&'static str, fn() -> Grammar),
+	Concat(Vec<Grammar>),
+	OneOf(Vec<Grammar>),
+	OneOrMore(Box<Grammar>),
+	ZeroOrMore(Box<Grammar>),
+	ZeroOrOne(Box<Grammar>),
+}
+
+impl Grammar {
+	// Renders this fragment as EBNF. Named rules are expanded into their own
+	// `Name = Body ;` line the first time they're reached, and referred to by
+	// name on every later occurrence. Only named rules produce lines: a
+	// fragment that is not itself a `Rule` is not rendered, only the rules
+	// it reaches.
+	pub fn to_ebnf(&self) -> String {
+		let mut out = String::new();
+		let mut seen = Vec::new();
+		collect_rules(self, &mut out, &mut seen);
+		out
+	}
+}
+
+fn collect_rules(grammar: &Grammar, out: &mut String, seen: &mut Vec<&'static str>) {
+	match grammar {
+		Grammar::Rule(name, body_fn) => {
+			if seen.contains(name) {
+				return;
+			}
+			seen.push(name);
+			let body = body_fn();
+			collect_rules(&body, out, seen);
+			out.push_str(&format!("{} = {} ;\n", name, render(&body)));
+		}
+		Grammar::Concat(parts) | Grammar::OneOf(parts) => {
+			for part in parts {
+				collect_rules(part, out, seen);
+			}
+		}
+		Grammar::OneOrMore(inner) | Grammar::ZeroOrMore(inner) | Grammar::ZeroOrOne(inner) => {
+			collect_rules(inner, out, seen);
+		}
+		Grammar::Terminal(_) => {}
+	}
+}
+
+fn render(grammar: &Grammar) -> String {
+	match grammar {
+		Grammar::Terminal(name) => String::from(*name),
+		Grammar::Rule(name, _) => String::from(*name),
+		Grammar::Concat(parts) => parts.iter().map(render).collect::<Vec<_>>().join(" , "),
+		Grammar::OneOf(parts) => parts.iter().map(render).collect::<Vec<_>>().join(" | "),
+		Grammar::OneOrMore(inner) => format!("{} , {{ {} }}", render(inner), render(inner)),
+		Grammar::ZeroOrMore(inner) => format!("{{ {} }}", render(inner)),
+		Grammar::ZeroOrOne(inner) => format!("[ {} ]", render(inner)),
+	}
+}
+
+// Implemented by every parser combinator so its grammar can be rendered as
+// EBNF, parallel to `Parser` itself.
+pub trait Describe {
+	fn describe() -> Grammar;
+}
+
+impl<P: Describe, P2: Describe> Describe for Concat<P, P2> {
+	fn describe() -> Grammar {
+		Grammar::Concat(vec![P::describe(), P2::describe()])
+	}
+}
+
+impl<P: Describe, P2: Describe> Describe for OneOf<P, P2> {
+	fn describe() -> Grammar {
+		Grammar::OneOf(vec![P::describe(), P2::describe()])
+	}
+}
+
+impl<P: Describe> Describe for OneOrMore<P> {
+	fn describe() -> Grammar {
+		Grammar::OneOrMore(Box::new(P::describe()))
+	}
+}
+
+impl<P: Describe> Describe for ZeroOrMore<P> {
+	fn describe() -> Grammar {
+		Grammar::ZeroOrMore(Box::new(P::describe()))
+	}
+}
+
+impl<P: Describe> Describe for ZeroOrOne<P> {
+	fn describe() -> Grammar {
+		Grammar::ZeroOrOne(Box::new(P::describe()))
+	}
+}
+
+impl Describe for Null {
+	fn describe() -> Grammar {
+		Grammar::Terminal("ε")
+	}
+}
@@ -49,15 +49,18 @@ parsers! {
 		}
 	};
 
-	pub NegativeInteger = Concat<NegativeSignChar, PositiveInteger>, i64, (output) => {
+	pub NegativeInteger = Concat<NegativeSignChar, PositiveInteger>, u64, (output) => {
 		let (_, output) = output;
-		- (output as i64)
+		output
 	};
 
-	pub Integer = OneOf<PositiveInteger, NegativeInteger>, i64, (output) => {
+	// Carries the sign alongside the magnitude: the magnitude alone can't
+	// distinguish "0" from "-0", which matters once a fraction like "-0.5"
+	// is attached to it.
+	pub Integer = OneOf<PositiveInteger, NegativeInteger>, (bool, u64), (output) => {
 		match output {
-			Either::A(a) => a as i64,
-			Either::B(b) => b,
+			Either::A(a) => (false, a),
+			Either::B(b) => (true, b),
 		}
 	};
 
@@ -92,9 +95,10 @@ parsers! {
 	};
 
 	pub Number = Concat3<Integer, Fraction, Exponent>, NumberValue, (output) => {
-		let (n, (f, e)) = output;
+		let ((negative, magnitude), (f, e)) = output;
 		NumberValue {
-			integer: n,
+			negative,
+			integer: if negative { - (magnitude as i64) } else { magnitude as i64 },
 			fraction: f.0,
 			fraction_length: f.1,
 			exponent: e,
@@ -196,6 +200,7 @@ pub struct Value;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct NumberValue {
+	pub negative: bool,
 	pub integer: i64,
 	pub fraction: u64,
 	pub fraction_length: u32,
@@ -277,6 +282,10 @@ impl JsonValue {
 		}
 		false
 	}
+
+	pub fn select(&self, path: &str) -> Result<Vec<&JsonValue>, SimpleError> {
+		crate::path::select(self, path)
+	}
 }
 
 impl<I: Input> Parser<I> for Value
@@ -375,3 +384,128 @@ impl<I: Input> Parser<I> for Array {
 }
 
 pub type Json = Element;
+
+use crate::grammar::{Describe, Grammar};
+use alloc::boxed::Box;
+
+impl Describe for Escape {
+	fn describe() -> Grammar {
+		fn body() -> Grammar {
+			Grammar::Terminal("escape sequence")
+		}
+		Grammar::Rule("Escape", body)
+	}
+}
+
+impl Describe for Character {
+	fn describe() -> Grammar {
+		fn body() -> Grammar {
+			Grammar::OneOf(alloc::vec![
+				Grammar::Terminal("any character except '\"' or '\\'"),
+				Escape::describe(),
+			])
+		}
+		Grammar::Rule("Character", body)
+	}
+}
+
+impl Describe for Member {
+	fn describe() -> Grammar {
+		fn body() -> Grammar {
+			Grammar::Concat(alloc::vec![
+				Whitespace::describe(),
+				String::describe(),
+				Whitespace::describe(),
+				Grammar::Terminal("':'"),
+				Element::describe(),
+			])
+		}
+		Grammar::Rule("Member", body)
+	}
+}
+
+impl Describe for Element {
+	fn describe() -> Grammar {
+		fn body() -> Grammar {
+			Grammar::Concat(alloc::vec![
+				Whitespace::describe(),
+				Value::describe(),
+				Whitespace::describe(),
+			])
+		}
+		Grammar::Rule("Element", body)
+	}
+}
+
+impl Describe for Members {
+	fn describe() -> Grammar {
+		fn body() -> Grammar {
+			Grammar::Concat(alloc::vec![
+				Member::describe(),
+				Grammar::ZeroOrMore(Box::new(Grammar::Concat(alloc::vec![
+					Grammar::Terminal("','"),
+					Member::describe(),
+				]))),
+			])
+		}
+		Grammar::Rule("Members", body)
+	}
+}
+
+impl Describe for Object {
+	fn describe() -> Grammar {
+		fn body() -> Grammar {
+			Grammar::Concat(alloc::vec![
+				Grammar::Terminal("'{'"),
+				Grammar::OneOf(alloc::vec![Members::describe(), Whitespace::describe()]),
+				Grammar::Terminal("'}'"),
+			])
+		}
+		Grammar::Rule("Object", body)
+	}
+}
+
+impl Describe for Elements {
+	fn describe() -> Grammar {
+		fn body() -> Grammar {
+			Grammar::Concat(alloc::vec![
+				Element::describe(),
+				Grammar::ZeroOrMore(Box::new(Grammar::Concat(alloc::vec![
+					Grammar::Terminal("','"),
+					Element::describe(),
+				]))),
+			])
+		}
+		Grammar::Rule("Elements", body)
+	}
+}
+
+impl Describe for Array {
+	fn describe() -> Grammar {
+		fn body() -> Grammar {
+			Grammar::Concat(alloc::vec![
+				Grammar::Terminal("'['"),
+				Elements::describe(),
+				Grammar::Terminal("']'"),
+			])
+		}
+		Grammar::Rule("Array", body)
+	}
+}
+
+impl Describe for Value {
+	fn describe() -> Grammar {
+		fn body() -> Grammar {
+			Grammar::OneOf(alloc::vec![
+				Object::describe(),
+				Array::describe(),
+				String::describe(),
+				Number::describe(),
+				Grammar::Terminal("'null'"),
+				Grammar::Terminal("'true'"),
+				Grammar::Terminal("'false'"),
+			])
+		}
+		Grammar::Rule("Value", body)
+	}
+}
@@ -108,9 +108,23 @@ pub type OneOf8<P, P2, P3, P4, P5, P6, P7, P8> = OneOf<P, OneOf7<P2, P3, P4, P5,
 pub type OneOf9<P, P2, P3, P4, P5, P6, P7, P8, P9> =
 	OneOf<P, OneOf8<P2, P3, P4, P5, P6, P7, P8, P9>>;
 
-pub type ZeroOrOne<P> = OneOf<P, Null>;
+pub struct ZeroOrOne<P>(PhantomData<P>);
 
-pub type ZeroOrMore<P> = OneOf<OneOrMore<P>, Null>;
+impl<I: Input, P: Parser<I>> Parser<I> for ZeroOrOne<P> {
+	type Output = <OneOf<P, Null> as Parser<I>>::Output;
+	fn parse(input: &I, current: I::Position) -> ResultOf<I, Self::Output> {
+		<OneOf<P, Null> as Parser<I>>::parse(input, current)
+	}
+}
+
+pub struct ZeroOrMore<P>(PhantomData<P>);
+
+impl<I: Input, P: Parser<I>> Parser<I> for ZeroOrMore<P> {
+	type Output = <OneOf<OneOrMore<P>, Null> as Parser<I>>::Output;
+	fn parse(input: &I, current: I::Position) -> ResultOf<I, Self::Output> {
+		<OneOf<OneOrMore<P>, Null> as Parser<I>>::parse(input, current)
+	}
+}
 
 //pub type OneOrMore<P> = Concat<P, ZeroOrMore<P>>;
 pub struct OneOrMore<P>(PhantomData<P>);
@@ -203,6 +217,12 @@ macro_rules! literals {
 
 			$( #[ $attr ] )*
 			$vis type $name = $crate::parser::ExpectChar<[< $name Predicate >]>;
+
+			impl $crate::grammar::Describe for $name {
+				fn describe() -> $crate::grammar::Grammar {
+					$crate::grammar::Grammar::Terminal(stringify!($name))
+				}
+			}
 		}
 	);
 }
@@ -226,6 +246,15 @@ macro_rules! parsers {
 					Ok((res, pos))
 				}
 			}
+
+			impl $crate::grammar::Describe for $name {
+				fn describe() -> $crate::grammar::Grammar {
+					fn body() -> $crate::grammar::Grammar {
+						<$type as $crate::grammar::Describe>::describe()
+					}
+					$crate::grammar::Grammar::Rule(stringify!($name), body)
+				}
+			}
 		)*
 	};
 }
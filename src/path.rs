@@ -0,0 +1,246 @@
+extern crate alloc;
+use alloc::{string::String as AllocString, vec::Vec};
+
+use crate::impls::SimpleError;
+use crate::json::JsonValue;
+use crate::parser::Error;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PathStep {
+	Root,
+	Child(AllocString),
+	Index(i64),
+	Slice(Option<i64>, Option<i64>),
+	Wildcard,
+	RecursiveDescent,
+}
+
+pub fn select<'a>(value: &'a JsonValue, path: &str) -> Result<Vec<&'a JsonValue>, SimpleError> {
+	let steps = parse_path(path)?;
+	let mut current = alloc::vec![value];
+	for step in &steps {
+		current = apply_step(current, step)?;
+	}
+	Ok(current)
+}
+
+fn parse_path(path: &str) -> Result<Vec<PathStep>, SimpleError> {
+	let mut chars = path.chars().peekable();
+	let mut steps = Vec::new();
+	match chars.next() {
+		Some('$') => steps.push(PathStep::Root),
+		_ => return Err(SimpleError::plain_str("path must start with '$'")),
+	}
+	while let Some(&c) = chars.peek() {
+		match c {
+			'.' => {
+				chars.next();
+				if chars.peek() == Some(&'.') {
+					chars.next();
+					steps.push(PathStep::RecursiveDescent);
+					// `..` can be followed by a bracket step directly
+					// (`$..[0]`, `$..['key']`) as well as by `.name` or
+					// `.*`, so all three are handled here rather than just
+					// falling through to the outer `.`/`[` match.
+					if chars.peek() == Some(&'*') {
+						chars.next();
+						steps.push(PathStep::Wildcard);
+					} else if chars.peek() == Some(&'[') {
+						chars.next();
+						steps.push(read_bracket(&mut chars)?);
+					} else {
+						steps.push(PathStep::Child(read_ident(&mut chars)?));
+					}
+					continue;
+				}
+				if chars.peek() == Some(&'*') {
+					chars.next();
+					steps.push(PathStep::Wildcard);
+					continue;
+				}
+				steps.push(PathStep::Child(read_ident(&mut chars)?));
+			}
+			'[' => {
+				chars.next();
+				steps.push(read_bracket(&mut chars)?);
+			}
+			_ => return Err(SimpleError::plain_str("unexpected character in path")),
+		}
+	}
+	Ok(steps)
+}
+
+fn read_ident(chars: &mut core::iter::Peekable<core::str::Chars>) -> Result<AllocString, SimpleError> {
+	let mut name = AllocString::new();
+	while let Some(&c) = chars.peek() {
+		if c == '.' || c == '[' {
+			break;
+		}
+		name.push(c);
+		chars.next();
+	}
+	if name.is_empty() {
+		return Err(SimpleError::plain_str("expected a field name in path"));
+	}
+	Ok(name)
+}
+
+fn read_bracket(chars: &mut core::iter::Peekable<core::str::Chars>) -> Result<PathStep, SimpleError> {
+	match chars.peek() {
+		Some(&'*') => {
+			chars.next();
+			expect_char(chars, ']')?;
+			Ok(PathStep::Wildcard)
+		}
+		Some(&quote @ ('\'' | '"')) => {
+			chars.next();
+			let mut name = AllocString::new();
+			loop {
+				match chars.next() {
+					Some(c) if c == quote => break,
+					Some(c) => name.push(c),
+					None => return Err(SimpleError::plain_str("unterminated quoted key in path")),
+				}
+			}
+			expect_char(chars, ']')?;
+			Ok(PathStep::Child(name))
+		}
+		_ => {
+			let mut token = AllocString::new();
+			while let Some(&c) = chars.peek() {
+				if c == ']' {
+					break;
+				}
+				token.push(c);
+				chars.next();
+			}
+			expect_char(chars, ']')?;
+			if let Some(colon_pos) = token.find(':') {
+				let start = parse_opt_i64(&token[..colon_pos])?;
+				let end = parse_opt_i64(&token[colon_pos + 1..])?;
+				Ok(PathStep::Slice(start, end))
+			} else {
+				let index = token
+					.parse::<i64>()
+					.map_err(|_| SimpleError::plain_str("invalid index in path"))?;
+				Ok(PathStep::Index(index))
+			}
+		}
+	}
+}
+
+fn expect_char(chars: &mut core::iter::Peekable<core::str::Chars>, expected: char) -> Result<(), SimpleError> {
+	match chars.next() {
+		Some(c) if c == expected => Ok(()),
+		_ => Err(SimpleError::plain_str("malformed path expression")),
+	}
+}
+
+fn parse_opt_i64(s: &str) -> Result<Option<i64>, SimpleError> {
+	if s.is_empty() {
+		Ok(None)
+	} else {
+		s.parse::<i64>()
+			.map(Some)
+			.map_err(|_| SimpleError::plain_str("invalid slice bound in path"))
+	}
+}
+
+fn apply_step<'a>(current: Vec<&'a JsonValue>, step: &PathStep) -> Result<Vec<&'a JsonValue>, SimpleError> {
+	match step {
+		PathStep::Root => Ok(current),
+		PathStep::Child(name) => {
+			let mut out = Vec::new();
+			for value in current {
+				if let JsonValue::Object(members) = value {
+					for (key, v) in members {
+						if key.iter().collect::<AllocString>() == *name {
+							out.push(v);
+						}
+					}
+				}
+			}
+			Ok(out)
+		}
+		PathStep::Index(index) => {
+			let mut out = Vec::new();
+			for value in current {
+				if let JsonValue::Array(items) = value {
+					if let Some(v) = resolve_index(items, *index) {
+						out.push(v);
+					}
+				}
+			}
+			Ok(out)
+		}
+		PathStep::Slice(start, end) => {
+			let mut out = Vec::new();
+			for value in current {
+				if let JsonValue::Array(items) = value {
+					let (lo, hi) = resolve_slice(items.len(), *start, *end);
+					out.extend(items[lo..hi].iter());
+				}
+			}
+			Ok(out)
+		}
+		PathStep::Wildcard => {
+			let mut out = Vec::new();
+			for value in current {
+				match value {
+					JsonValue::Object(members) => out.extend(members.iter().map(|(_, v)| v)),
+					JsonValue::Array(items) => out.extend(items.iter()),
+					_ => {}
+				}
+			}
+			Ok(out)
+		}
+		PathStep::RecursiveDescent => {
+			let mut out = Vec::new();
+			for value in current {
+				collect_descendants(value, &mut out);
+			}
+			Ok(out)
+		}
+	}
+}
+
+fn collect_descendants<'a>(value: &'a JsonValue, out: &mut Vec<&'a JsonValue>) {
+	out.push(value);
+	match value {
+		JsonValue::Object(members) => {
+			for (_, v) in members {
+				collect_descendants(v, out);
+			}
+		}
+		JsonValue::Array(items) => {
+			for v in items {
+				collect_descendants(v, out);
+			}
+		}
+		_ => {}
+	}
+}
+
+fn resolve_index(items: &[JsonValue], index: i64) -> Option<&JsonValue> {
+	let len = items.len() as i64;
+	let idx = if index < 0 { len + index } else { index };
+	if idx < 0 || idx >= len {
+		None
+	} else {
+		items.get(idx as usize)
+	}
+}
+
+fn resolve_slice(len: usize, start: Option<i64>, end: Option<i64>) -> (usize, usize) {
+	let len_i = len as i64;
+	let norm = |v: i64| -> i64 {
+		if v < 0 {
+			(len_i + v).max(0)
+		} else {
+			v.min(len_i)
+		}
+	};
+	let lo = start.map(norm).unwrap_or(0).max(0) as usize;
+	let hi = end.map(norm).unwrap_or(len_i).max(lo as i64) as usize;
+	(lo, hi)
+}
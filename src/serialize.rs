@@ -0,0 +1,134 @@
+extern crate alloc;
+use alloc::string::String as AllocString;
+use core::fmt::Write as _;
+
+use crate::json::{JsonObject, JsonValue, NumberValue};
+
+pub struct PrettyConfig {
+	pub indent: usize,
+}
+
+impl Default for PrettyConfig {
+	fn default() -> Self {
+		PrettyConfig { indent: 2 }
+	}
+}
+
+pub fn to_string(value: &JsonValue) -> AllocString {
+	let mut out = AllocString::new();
+	write_value(&mut out, value, None, 0);
+	out
+}
+
+pub fn to_string_pretty(value: &JsonValue, config: &PrettyConfig) -> AllocString {
+	let mut out = AllocString::new();
+	write_value(&mut out, value, Some(config), 0);
+	out
+}
+
+fn write_value(out: &mut AllocString, value: &JsonValue, pretty: Option<&PrettyConfig>, depth: usize) {
+	match value {
+		JsonValue::Object(members) => write_object(out, members, pretty, depth),
+		JsonValue::Array(elements) => write_array(out, elements, pretty, depth),
+		JsonValue::String(chars) => write_escaped_string(out, chars),
+		JsonValue::Number(num) => write_number(out, num),
+		JsonValue::Boolean(b) => out.push_str(if *b { "true" } else { "false" }),
+		JsonValue::Null => out.push_str("null"),
+	}
+}
+
+fn write_object(out: &mut AllocString, members: &JsonObject, pretty: Option<&PrettyConfig>, depth: usize) {
+	out.push('{');
+	if members.is_empty() {
+		out.push('}');
+		return;
+	}
+	for (i, (key, value)) in members.iter().enumerate() {
+		if i > 0 {
+			out.push(',');
+		}
+		write_newline_indent(out, pretty, depth + 1);
+		write_escaped_string(out, key);
+		out.push(':');
+		if pretty.is_some() {
+			out.push(' ');
+		}
+		write_value(out, value, pretty, depth + 1);
+	}
+	write_newline_indent(out, pretty, depth);
+	out.push('}');
+}
+
+fn write_array(out: &mut AllocString, elements: &[JsonValue], pretty: Option<&PrettyConfig>, depth: usize) {
+	out.push('[');
+	if elements.is_empty() {
+		out.push(']');
+		return;
+	}
+	for (i, value) in elements.iter().enumerate() {
+		if i > 0 {
+			out.push(',');
+		}
+		write_newline_indent(out, pretty, depth + 1);
+		write_value(out, value, pretty, depth + 1);
+	}
+	write_newline_indent(out, pretty, depth);
+	out.push(']');
+}
+
+fn write_newline_indent(out: &mut AllocString, pretty: Option<&PrettyConfig>, depth: usize) {
+	if let Some(config) = pretty {
+		out.push('\n');
+		for _ in 0..depth * config.indent {
+			out.push(' ');
+		}
+	}
+}
+
+fn write_escaped_string(out: &mut AllocString, chars: &[char]) {
+	out.push('"');
+	for &c in chars {
+		match c {
+			'"' => out.push_str("\\\""),
+			'\\' => out.push_str("\\\\"),
+			'\u{0008}' => out.push_str("\\b"),
+			'\u{000C}' => out.push_str("\\f"),
+			'\n' => out.push_str("\\n"),
+			'\r' => out.push_str("\\r"),
+			'\t' => out.push_str("\\t"),
+			c if (c as u32) < 0x20 => {
+				let _ = write!(out, "\\u{:04x}", c as u32);
+			}
+			c if (c as u32) > 0x7F => {
+				let code = c as u32;
+				if code > 0xFFFF {
+					let v = code - 0x10000;
+					let high = 0xD800 + (v >> 10);
+					let low = 0xDC00 + (v & 0x3FF);
+					let _ = write!(out, "\\u{:04x}\\u{:04x}", high, low);
+				} else {
+					let _ = write!(out, "\\u{:04x}", code);
+				}
+			}
+			c => out.push(c),
+		}
+	}
+	out.push('"');
+}
+
+// Reconstructs the textual number from its parsed parts instead of going
+// through `NumberValue`'s lossy `Into<f64>`, so round-tripping is exact. The
+// sign is read off `negative` rather than `integer`, since "-0.5" parses to
+// an `integer` of 0, which can't carry a sign on its own.
+fn write_number(out: &mut AllocString, num: &NumberValue) {
+	if num.negative {
+		out.push('-');
+	}
+	let _ = write!(out, "{}", num.integer.unsigned_abs());
+	if num.fraction_length > 0 {
+		let _ = write!(out, ".{:0width$}", num.fraction, width = num.fraction_length as usize);
+	}
+	if num.exponent != 0 {
+		let _ = write!(out, "e{}", num.exponent);
+	}
+}
@@ -0,0 +1,219 @@
+extern crate alloc;
+use alloc::vec::Vec;
+
+use crate::json::{JsonValue, Number, String as JsonString, Whitespace};
+use crate::parser::{Error, Input, Parser};
+
+// Flat token stream produced by `EventParser`, mirroring the `JsonEvent` pull
+// parser approach: a document is read one event at a time instead of being
+// built into a `JsonValue` tree, so memory stays bounded in the depth of the
+// document rather than its total size.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonEvent<P> {
+	ObjectStart(P),
+	ObjectEnd(P),
+	ArrayStart(P),
+	ArrayEnd(P),
+	Key(Vec<char>, P),
+	Value(JsonValue, P),
+	Eof(P),
+}
+
+enum StackElement {
+	InObject,
+	InArray,
+}
+
+enum ParseState {
+	Start,
+	ObjectKey,
+	ObjectColon,
+	ObjectComma,
+	ArrayValue,
+	ArrayComma,
+	End,
+}
+
+pub struct EventParser<I: Input> {
+	stack: Vec<StackElement>,
+	state: ParseState,
+	pos: I::Position,
+}
+
+impl<I: Input> EventParser<I> {
+	pub fn new(start: I::Position) -> Self {
+		EventParser {
+			stack: Vec::new(),
+			state: ParseState::Start,
+			pos: start,
+		}
+	}
+
+	pub fn next_event(&mut self, input: &I) -> Result<JsonEvent<I::Position>, I::Error> {
+		match self.state {
+			ParseState::End => Ok(JsonEvent::Eof(self.pos)),
+			ParseState::Start => self.parse_value(input),
+			ParseState::ObjectKey => self.parse_object_key(input),
+			ParseState::ObjectColon => self.parse_object_colon(input),
+			ParseState::ObjectComma => self.parse_object_comma(input),
+			ParseState::ArrayValue => self.parse_array_value(input),
+			ParseState::ArrayComma => self.parse_array_comma(input),
+		}
+	}
+
+	fn skip_whitespace(&mut self, input: &I) -> Result<(), I::Error> {
+		let (_, next) = <Whitespace as Parser<I>>::parse(input, self.pos)?;
+		self.pos = next;
+		Ok(())
+	}
+
+	// After any complete value (scalar, or a closed object/array), the next
+	// token depends on what's enclosing us: a comma-or-close inside a
+	// container, or end of input at the top level.
+	fn after_value(&mut self) {
+		self.state = match self.stack.last() {
+			Some(StackElement::InObject) => ParseState::ObjectComma,
+			Some(StackElement::InArray) => ParseState::ArrayComma,
+			None => ParseState::End,
+		};
+	}
+
+	fn parse_value(&mut self, input: &I) -> Result<JsonEvent<I::Position>, I::Error> {
+		self.skip_whitespace(input)?;
+		let start = self.pos;
+		let (c, next) = input.next(start)?;
+		match c {
+			'{' => {
+				self.pos = next;
+				self.stack.push(StackElement::InObject);
+				self.state = ParseState::ObjectKey;
+				Ok(JsonEvent::ObjectStart(start))
+			}
+			'[' => {
+				self.pos = next;
+				self.stack.push(StackElement::InArray);
+				self.state = ParseState::ArrayValue;
+				Ok(JsonEvent::ArrayStart(start))
+			}
+			_ => self.parse_scalar(input, start),
+		}
+	}
+
+	fn parse_scalar(&mut self, input: &I, start: I::Position) -> Result<JsonEvent<I::Position>, I::Error> {
+		if let Ok((value, next)) = <JsonString as Parser<I>>::parse(input, start) {
+			self.pos = next;
+			self.after_value();
+			return Ok(JsonEvent::Value(JsonValue::String(value), start));
+		}
+		if let Ok((value, next)) = <Number as Parser<I>>::parse(input, start) {
+			self.pos = next;
+			self.after_value();
+			return Ok(JsonEvent::Value(JsonValue::Number(value), start));
+		}
+		if let Ok((value, next)) = input.next_range(start, 4) {
+			if value == "null" {
+				self.pos = next;
+				self.after_value();
+				return Ok(JsonEvent::Value(JsonValue::Null, start));
+			}
+			if value == "true" {
+				self.pos = next;
+				self.after_value();
+				return Ok(JsonEvent::Value(JsonValue::Boolean(true), start));
+			}
+		}
+		if let Ok((value, next)) = input.next_range(start, 5) {
+			if value == "false" {
+				self.pos = next;
+				self.after_value();
+				return Ok(JsonEvent::Value(JsonValue::Boolean(false), start));
+			}
+		}
+		Err(input.error_at(start, "EventParser"))
+	}
+
+	fn parse_object_key(&mut self, input: &I) -> Result<JsonEvent<I::Position>, I::Error> {
+		self.skip_whitespace(input)?;
+		let start = self.pos;
+		if let Ok(('}', next)) = input.next(start) {
+			self.pos = next;
+			self.stack.pop();
+			self.after_value();
+			return Ok(JsonEvent::ObjectEnd(start));
+		}
+		self.parse_key(input)
+	}
+
+	// Unlike `parse_object_key`, this never treats an immediate `}` as valid:
+	// it's reached right after a comma, where the grammar requires another
+	// member and a `}` there means a trailing comma, or from
+	// `parse_object_key` once an immediate `}` has already been ruled out.
+	fn parse_key(&mut self, input: &I) -> Result<JsonEvent<I::Position>, I::Error> {
+		self.skip_whitespace(input)?;
+		let start = self.pos;
+		let (key, next) = <JsonString as Parser<I>>::parse(input, start)
+			.map_err(|e| e.add_reason(Some(start), "EventParser"))?;
+		self.pos = next;
+		self.state = ParseState::ObjectColon;
+		Ok(JsonEvent::Key(key, start))
+	}
+
+	fn parse_object_colon(&mut self, input: &I) -> Result<JsonEvent<I::Position>, I::Error> {
+		self.skip_whitespace(input)?;
+		let colon_pos = self.pos;
+		let (c, next) = input.next(colon_pos)?;
+		if c != ':' {
+			return Err(input.error_at(colon_pos, "EventParser"));
+		}
+		self.pos = next;
+		self.parse_value(input)
+	}
+
+	fn parse_object_comma(&mut self, input: &I) -> Result<JsonEvent<I::Position>, I::Error> {
+		self.skip_whitespace(input)?;
+		let start = self.pos;
+		let (c, next) = input.next(start)?;
+		match c {
+			',' => {
+				self.pos = next;
+				self.parse_key(input)
+			}
+			'}' => {
+				self.pos = next;
+				self.stack.pop();
+				self.after_value();
+				Ok(JsonEvent::ObjectEnd(start))
+			}
+			_ => Err(input.error_at(start, "EventParser")),
+		}
+	}
+
+	// Unlike `parse_object_key`, there's no immediate-`]` case here: `Array`
+	// requires at least one element, so the position right after `[` is
+	// parsed the same way as any other value position.
+	fn parse_array_value(&mut self, input: &I) -> Result<JsonEvent<I::Position>, I::Error> {
+		self.parse_value(input)
+	}
+
+	// Reached after an element: a `]` closes the array, while a `,` must be
+	// followed by another value, so `parse_value` rejects a `]` there as a
+	// trailing comma.
+	fn parse_array_comma(&mut self, input: &I) -> Result<JsonEvent<I::Position>, I::Error> {
+		self.skip_whitespace(input)?;
+		let start = self.pos;
+		let (c, next) = input.next(start)?;
+		match c {
+			',' => {
+				self.pos = next;
+				self.parse_value(input)
+			}
+			']' => {
+				self.pos = next;
+				self.stack.pop();
+				self.after_value();
+				Ok(JsonEvent::ArrayEnd(start))
+			}
+			_ => Err(input.error_at(start, "EventParser")),
+		}
+	}
+}